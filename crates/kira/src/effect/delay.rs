@@ -0,0 +1,134 @@
+//! Produces echoes of the input audio.
+
+mod builder;
+mod handle;
+
+pub use builder::*;
+pub use handle::*;
+
+use crate::{
+	command::{read_commands_into_parameters, ValueChangeCommand},
+	command_writers_and_readers,
+	frame::Frame,
+	info::Info,
+	mix::Mix,
+	tween::Parameter,
+};
+
+use super::Effect;
+
+/// The highest sample rate the delay buffer is sized to support.
+///
+/// The buffer has to be allocated up front in [`Delay::new`] rather than
+/// lazily on the audio thread (a heap allocation in `process` would risk an
+/// audible glitch), which means it has to be sized before the real sample
+/// rate is known from `dt`. This covers every sample rate in practical use.
+const ASSUMED_MAX_SAMPLE_RATE: f64 = 192_000.0;
+
+struct Delay {
+	command_readers: CommandReaders,
+	delay_time: Parameter,
+	feedback: Parameter,
+	mix: Parameter<Mix>,
+	max_delay_time: f64,
+	buffer: Vec<Frame>,
+	write_position: usize,
+}
+
+impl Delay {
+	#[must_use]
+	fn new(builder: DelayBuilder, command_readers: CommandReaders) -> Self {
+		let length = (builder.max_delay_time * ASSUMED_MAX_SAMPLE_RATE).ceil() as usize;
+		Self {
+			command_readers,
+			delay_time: Parameter::new(builder.delay_time, 0.5),
+			feedback: Parameter::new(builder.feedback, 0.5),
+			mix: Parameter::new(builder.mix, Mix(0.5)),
+			max_delay_time: builder.max_delay_time,
+			buffer: vec![Frame::new(0.0, 0.0); length.max(1)],
+			write_position: 0,
+		}
+	}
+}
+
+impl Effect for Delay {
+	fn on_start_processing(&mut self) {
+		read_commands_into_parameters!(self, delay_time, feedback, mix);
+	}
+
+	fn process(&mut self, input: Frame, dt: f64, info: &Info) -> Frame {
+		self.delay_time.update(dt, info);
+		self.feedback.update(dt, info);
+		self.mix.update(dt, info);
+
+		let sample_rate = 1.0 / dt;
+		let buffer = &mut self.buffer;
+
+		let delay_time = self.delay_time.value().clamp(0.0, self.max_delay_time);
+		let delay_samples = (delay_time * sample_rate) as usize % buffer.len();
+		let wet = match read_position(self.write_position, buffer.len(), delay_samples) {
+			Some(read_position) => buffer[read_position],
+			None => input,
+		};
+
+		let feedback = self.feedback.value().clamp(0.0, MAX_FEEDBACK) as f32;
+		buffer[self.write_position] = Frame::new(
+			input.left + wet.left * feedback,
+			input.right + wet.right * feedback,
+		);
+		self.write_position = (self.write_position + 1) % buffer.len();
+
+		let mix = self.mix.value().0.clamp(Mix::DRY.0, Mix::WET.0);
+		Frame::new(
+			input.left + (wet.left - input.left) * mix,
+			input.right + (wet.right - input.right) * mix,
+		)
+	}
+}
+
+command_writers_and_readers! {
+	set_delay_time: ValueChangeCommand<f64>,
+	set_feedback: ValueChangeCommand<f64>,
+	set_mix: ValueChangeCommand<Mix>,
+}
+
+/// Finds the position to read the delayed sample from, `delay_samples`
+/// behind `write_position` in a ring buffer of length `buffer_len`.
+///
+/// Returns `None` when `delay_samples` is `0`, since the read and write
+/// positions would otherwise collide - reading the sample written a full
+/// `buffer_len` frames ago instead of passing the current input straight
+/// through.
+fn read_position(write_position: usize, buffer_len: usize, delay_samples: usize) -> Option<usize> {
+	if delay_samples == 0 {
+		None
+	} else {
+		Some((write_position + buffer_len - delay_samples) % buffer_len)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn zero_delay_passes_through() {
+		assert_eq!(read_position(0, 10, 0), None);
+		assert_eq!(read_position(7, 10, 0), None);
+	}
+
+	#[test]
+	fn reads_behind_the_write_position() {
+		assert_eq!(read_position(5, 10, 3), Some(2));
+	}
+
+	#[test]
+	fn wraps_around_the_start_of_the_buffer() {
+		assert_eq!(read_position(2, 10, 5), Some(7));
+	}
+
+	#[test]
+	fn full_buffer_delay_reads_the_write_position() {
+		assert_eq!(read_position(4, 10, 10), Some(4));
+	}
+}
@@ -0,0 +1,81 @@
+use crate::{mix::Mix, Value};
+
+/// The maximum feedback amount allowed on a [`Delay`](super::Delay) effect.
+///
+/// Feedback values at or above `1.0` would cause the echoes to grow
+/// louder on every repeat instead of decaying, so the feedback
+/// parameter is always clamped to at most this value.
+pub const MAX_FEEDBACK: f64 = 0.99;
+
+/// Configures a [`Delay`](super::Delay) effect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DelayBuilder {
+	/// The time between each echo, in seconds.
+	pub delay_time: Value<f64>,
+	/// How much of the delayed signal is fed back into the delay line.
+	///
+	/// Clamped to `[0.0, MAX_FEEDBACK]` to avoid runaway gain.
+	pub feedback: Value<f64>,
+	/// How much of the delayed signal is mixed into the output.
+	pub mix: Value<Mix>,
+	/// The maximum delay time the effect can be configured with, in seconds.
+	///
+	/// This determines the size of the internal delay buffer, so it
+	/// cannot be changed after the effect is created.
+	pub max_delay_time: f64,
+}
+
+impl DelayBuilder {
+	/// The default maximum delay time, in seconds.
+	pub const DEFAULT_MAX_DELAY_TIME: f64 = 10.0;
+
+	/// Sets the time between each echo, in seconds.
+	#[must_use]
+	pub fn delay_time(self, delay_time: impl Into<Value<f64>>) -> Self {
+		Self {
+			delay_time: delay_time.into(),
+			..self
+		}
+	}
+
+	/// Sets how much of the delayed signal is fed back into the delay line.
+	#[must_use]
+	pub fn feedback(self, feedback: impl Into<Value<f64>>) -> Self {
+		Self {
+			feedback: feedback.into(),
+			..self
+		}
+	}
+
+	/// Sets how much of the delayed signal is mixed into the output.
+	#[must_use]
+	pub fn mix(self, mix: impl Into<Value<Mix>>) -> Self {
+		Self {
+			mix: mix.into(),
+			..self
+		}
+	}
+
+	/// Sets the maximum delay time the effect can be configured with, in seconds.
+	#[must_use]
+	pub fn max_delay_time(self, max_delay_time: f64) -> Self {
+		Self {
+			max_delay_time,
+			..self
+		}
+	}
+}
+
+impl Default for DelayBuilder {
+	fn default() -> Self {
+		Self {
+			delay_time: Value::Fixed(0.5),
+			feedback: Value::Fixed(0.5),
+			// unlike a filter, the "wet" signal here is a disjoint, time-shifted
+			// copy rather than a processed version of the dry signal, so a fully
+			// wet mix would mute the input entirely - default to an even blend.
+			mix: Mix(0.5).into(),
+			max_delay_time: Self::DEFAULT_MAX_DELAY_TIME,
+		}
+	}
+}
@@ -0,0 +1,35 @@
+use crate::{command::ValueChangeCommand, mix::Mix, tween::Tween, Value};
+
+use super::CommandWriters;
+
+/// Controls a [`Delay`](super::Delay) effect.
+#[derive(Debug)]
+pub struct DelayHandle {
+	pub(super) command_writers: CommandWriters,
+}
+
+impl DelayHandle {
+	/// Sets the time between each echo, in seconds.
+	pub fn set_delay_time(&mut self, delay_time: impl Into<Value<f64>>, tween: Tween) {
+		self.command_writers.set_delay_time.write(ValueChangeCommand {
+			target: delay_time.into(),
+			tween,
+		});
+	}
+
+	/// Sets how much of the delayed signal is fed back into the delay line.
+	pub fn set_feedback(&mut self, feedback: impl Into<Value<f64>>, tween: Tween) {
+		self.command_writers.set_feedback.write(ValueChangeCommand {
+			target: feedback.into(),
+			tween,
+		});
+	}
+
+	/// Sets how much of the delayed signal is mixed into the output.
+	pub fn set_mix(&mut self, mix: impl Into<Value<Mix>>, tween: Tween) {
+		self.command_writers.set_mix.write(ValueChangeCommand {
+			target: mix.into(),
+			tween,
+		});
+	}
+}
@@ -6,6 +6,8 @@ mod handle;
 pub use builder::*;
 pub use handle::*;
 
+use std::f32::consts::FRAC_PI_2;
+
 use crate::{
 	command::{read_commands_into_parameters, ValueChangeCommand},
 	command_writers_and_readers,
@@ -19,6 +21,7 @@ use super::Effect;
 struct PanningControl {
 	command_readers: CommandReaders,
 	panning: Parameter,
+	panning_law: PanningLaw,
 }
 
 impl PanningControl {
@@ -26,7 +29,8 @@ impl PanningControl {
 	fn new(builder: PanningControlBuilder, command_readers: CommandReaders) -> Self {
 		Self {
 			command_readers,
-			panning: Parameter::new(builder.0, 0.5),
+			panning: Parameter::new(builder.panning, 0.5),
+			panning_law: builder.panning_law,
 		}
 	}
 }
@@ -38,10 +42,129 @@ impl Effect for PanningControl {
 
 	fn process(&mut self, input: Frame, dt: f64, info: &Info) -> Frame {
 		self.panning.update(dt, info);
-		input.panned(self.panning.value() as f32)
+		// Clamp in case a tween overshoots the valid range (e.g. an elastic or
+		// back easing curve) - past [-1.0, 1.0], one of the `cos`/`sin` gains
+		// computed below would go negative, inverting a channel's phase
+		// instead of just hard-panning it.
+		let pan = (self.panning.value() as f32).clamp(-1.0, 1.0);
+		match self.panning_law {
+			PanningLaw::Linear => input.panned(pan),
+			PanningLaw::EqualPower => equal_power_panned(input, pan),
+		}
 	}
 }
 
 command_writers_and_readers! {
 	set_panning: ValueChangeCommand<f64>,
 }
+
+/// How close `input.left` and `input.right` need to be, relative to the
+/// frame's peak amplitude, to be blended towards the mono-equivalent
+/// panning curve in [`equal_power_panned`].
+const MONO_EQUIVALENCE_THRESHOLD: f32 = 1e-3;
+
+/// Pans a frame using the equal-power curve, following the algorithm used
+/// by the Web Audio `StereoPannerNode`.
+///
+/// Mono-equivalent input (where the left and right channels carry the same
+/// signal) is panned by simply splitting it between the two channels. Input
+/// that already carries distinct left/right content is panned by folding
+/// one channel progressively into the other, so a sound that's already
+/// positioned in its source material keeps that positioning as it pans.
+///
+/// `Frame` carries no metadata about whether it originated from a mono or
+/// stereo source, so which curve applies is decided from how close
+/// `input.left` is to `input.right`. Picking between the two curves with a
+/// hard cutoff would make content whose channels are merely correlated (but
+/// not bit-identical) flip between two formulas that disagree sharply at
+/// the extremes of the pan range, which would click. To avoid that, the two
+/// curves are cross-faded over [`MONO_EQUIVALENCE_THRESHOLD`] instead of
+/// switched on an exact `==` comparison.
+pub(super) fn equal_power_panned(input: Frame, pan: f32) -> Frame {
+	let peak = input.left.abs().max(input.right.abs()).max(f32::EPSILON);
+	let difference = (input.left - input.right).abs() / peak;
+	let mono_amount = (1.0 - difference / MONO_EQUIVALENCE_THRESHOLD).clamp(0.0, 1.0);
+
+	// The cross-fade window is narrow and rare in practice - most frames are
+	// either clearly mono-equivalent or clearly true-stereo, so avoid paying
+	// for both curves' `cos`/`sin` calls when only one is needed.
+	if mono_amount >= 1.0 {
+		return mono_equivalent_panned(input, pan);
+	}
+	if mono_amount <= 0.0 {
+		return stereo_folded_panned(input, pan);
+	}
+
+	let mono = mono_equivalent_panned(input, pan);
+	let stereo = stereo_folded_panned(input, pan);
+	Frame::new(
+		mono.left * mono_amount + stereo.left * (1.0 - mono_amount),
+		mono.right * mono_amount + stereo.right * (1.0 - mono_amount),
+	)
+}
+
+fn mono_equivalent_panned(input: Frame, pan: f32) -> Frame {
+	let x = (pan + 1.0) / 2.0;
+	let gain_left = (x * FRAC_PI_2).cos();
+	let gain_right = (x * FRAC_PI_2).sin();
+	Frame::new(input.left * gain_left, input.right * gain_right)
+}
+
+fn stereo_folded_panned(input: Frame, pan: f32) -> Frame {
+	if pan <= 0.0 {
+		let x = pan + 1.0;
+		Frame::new(
+			input.left + input.right * (x * FRAC_PI_2).cos(),
+			input.right * (x * FRAC_PI_2).sin(),
+		)
+	} else {
+		let x = pan;
+		Frame::new(
+			input.left * (x * FRAC_PI_2).cos(),
+			input.right + input.left * (x * FRAC_PI_2).sin(),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn mono_input_is_constant_power_across_the_pan_range() {
+		for pan in [-1.0, -0.5, -0.1, 0.0, 0.1, 0.5, 1.0] {
+			let panned = equal_power_panned(Frame::new(1.0, 1.0), pan);
+			let power = panned.left * panned.left + panned.right * panned.right;
+			assert!((power - 1.0).abs() < 1e-5, "power was {power} at pan {pan}");
+		}
+	}
+
+	#[test]
+	fn true_stereo_input_folds_fully_into_one_channel_at_hard_pan() {
+		let panned = equal_power_panned(Frame::new(0.5, 0.8), -1.0);
+		assert!((panned.right).abs() < 1e-6);
+		let panned = equal_power_panned(Frame::new(0.5, 0.8), 1.0);
+		assert!((panned.left).abs() < 1e-6);
+	}
+
+	#[test]
+	fn crossing_the_mono_equivalence_threshold_has_no_sudden_jump() {
+		// A signal whose channels drift from perfectly correlated to just barely
+		// distinct shouldn't produce an audible discontinuity as it crosses
+		// `MONO_EQUIVALENCE_THRESHOLD`, even at a hard pan where the mono and
+		// fully-stereo curves disagree the most.
+		let pan = -1.0;
+		let mut previous = equal_power_panned(Frame::new(1.0, 1.0), pan);
+		for steps in 1..=100 {
+			let right = 1.0 - steps as f32 * (MONO_EQUIVALENCE_THRESHOLD * 4.0 / 100.0);
+			let current = equal_power_panned(Frame::new(1.0, right), pan);
+			assert!(
+				(current.left - previous.left).abs() < 0.05,
+				"left channel jumped from {} to {} at step {steps}",
+				previous.left,
+				current.left
+			);
+			previous = current;
+		}
+	}
+}
@@ -0,0 +1,48 @@
+use crate::Value;
+
+pub use crate::panning::PanningLaw;
+
+/// Configures a [`PanningControl`](super::PanningControl) effect.
+///
+/// Note: this is a breaking change from the previous shape of this type,
+/// `PanningControlBuilder(pub Value<f64>)`. Any code constructing one as a
+/// tuple struct or reading its `.0` field needs to switch to the named
+/// `panning`/`panning_law` fields below (or the `PanningControlBuilder::new`
+/// constructor).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PanningControlBuilder {
+	/// The panning of the output audio. `-1.0` is hard left,
+	/// `1.0` is hard right, and `0.0` is center.
+	pub panning: Value<f64>,
+	/// The curve used to map the panning value to left/right gains.
+	pub panning_law: PanningLaw,
+}
+
+impl PanningControlBuilder {
+	/// Creates a new [`PanningControlBuilder`] with the given panning.
+	#[must_use]
+	pub fn new(panning: impl Into<Value<f64>>) -> Self {
+		Self {
+			panning: panning.into(),
+			..Self::default()
+		}
+	}
+
+	/// Sets the curve used to map the panning value to left/right gains.
+	#[must_use]
+	pub fn panning_law(self, panning_law: PanningLaw) -> Self {
+		Self {
+			panning_law,
+			..self
+		}
+	}
+}
+
+impl Default for PanningControlBuilder {
+	fn default() -> Self {
+		Self {
+			panning: Value::Fixed(0.0),
+			panning_law: PanningLaw::default(),
+		}
+	}
+}
@@ -0,0 +1,100 @@
+//! Positions audio around the listener by angle and distance.
+
+mod builder;
+mod handle;
+
+pub use builder::*;
+pub use handle::*;
+
+use crate::{
+	command::{read_commands_into_parameters, ValueChangeCommand},
+	command_writers_and_readers,
+	frame::Frame,
+	info::Info,
+	tween::Parameter,
+};
+
+use super::{panning_control::equal_power_panned, Effect};
+
+struct Spatial {
+	command_readers: CommandReaders,
+	azimuth: Parameter,
+	distance: Parameter,
+}
+
+impl Spatial {
+	#[must_use]
+	fn new(builder: SpatialBuilder, command_readers: CommandReaders) -> Self {
+		Self {
+			command_readers,
+			azimuth: Parameter::new(builder.azimuth, 0.0),
+			distance: Parameter::new(builder.distance, 0.0),
+		}
+	}
+}
+
+impl Effect for Spatial {
+	fn on_start_processing(&mut self) {
+		read_commands_into_parameters!(self, azimuth, distance);
+	}
+
+	fn process(&mut self, input: Frame, dt: f64, info: &Info) -> Frame {
+		self.azimuth.update(dt, info);
+		self.distance.update(dt, info);
+
+		let (pan, gain) = pan_and_gain(self.azimuth.value(), self.distance.value());
+
+		let panned = equal_power_panned(input, pan);
+		Frame::new(panned.left * gain, panned.right * gain)
+	}
+}
+
+command_writers_and_readers! {
+	set_azimuth: ValueChangeCommand<f64>,
+	set_distance: ValueChangeCommand<f64>,
+}
+
+/// Maps an azimuth (in degrees, `0.0` is front/center, increasing clockwise)
+/// and a distance (in `[0.0, 1.0]`) to a panning value and a volume gain.
+fn pan_and_gain(azimuth: f64, distance: f64) -> (f32, f32) {
+	let pan = azimuth.to_radians().sin().clamp(-1.0, 1.0) as f32;
+	let gain = (1.0 - distance.clamp(0.0, 1.0)) as f32;
+	(pan, gain)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn directly_in_front_is_centered() {
+		let (pan, _) = pan_and_gain(0.0, 0.0);
+		assert!(pan.abs() < 1e-6);
+	}
+
+	#[test]
+	fn ninety_degrees_clockwise_is_hard_right() {
+		let (pan, _) = pan_and_gain(90.0, 0.0);
+		assert!((pan - 1.0).abs() < 1e-6);
+	}
+
+	#[test]
+	fn ninety_degrees_counterclockwise_is_hard_left() {
+		let (pan, _) = pan_and_gain(-90.0, 0.0);
+		assert!((pan + 1.0).abs() < 1e-6);
+	}
+
+	#[test]
+	fn farther_away_is_quieter() {
+		let (_, near_gain) = pan_and_gain(0.0, 0.0);
+		let (_, far_gain) = pan_and_gain(0.0, 1.0);
+		assert!((near_gain - 1.0).abs() < 1e-6);
+		assert!(far_gain.abs() < 1e-6);
+	}
+
+	#[test]
+	fn distance_is_clamped_to_zero_one() {
+		let (_, gain) = pan_and_gain(0.0, 2.0);
+		assert!(gain >= 0.0);
+	}
+}
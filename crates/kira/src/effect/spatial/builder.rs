@@ -0,0 +1,53 @@
+use crate::Value;
+
+/// Configures a [`Spatial`](super::Spatial) effect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialBuilder {
+	/// The angle of the sound relative to the listener, in degrees.
+	///
+	/// `0.0` is directly in front of the listener, and the angle
+	/// increases clockwise.
+	pub azimuth: Value<f64>,
+	/// The distance of the sound from the listener, from `0.0`
+	/// (right on top of the listener) to `1.0` (as far away as
+	/// the sound can be heard).
+	pub distance: Value<f64>,
+}
+
+impl SpatialBuilder {
+	/// Creates a new [`SpatialBuilder`] with the given azimuth and distance.
+	#[must_use]
+	pub fn new(azimuth: impl Into<Value<f64>>, distance: impl Into<Value<f64>>) -> Self {
+		Self {
+			azimuth: azimuth.into(),
+			distance: distance.into(),
+		}
+	}
+
+	/// Sets the angle of the sound relative to the listener, in degrees.
+	#[must_use]
+	pub fn azimuth(self, azimuth: impl Into<Value<f64>>) -> Self {
+		Self {
+			azimuth: azimuth.into(),
+			..self
+		}
+	}
+
+	/// Sets the distance of the sound from the listener, from `0.0` to `1.0`.
+	#[must_use]
+	pub fn distance(self, distance: impl Into<Value<f64>>) -> Self {
+		Self {
+			distance: distance.into(),
+			..self
+		}
+	}
+}
+
+impl Default for SpatialBuilder {
+	fn default() -> Self {
+		Self {
+			azimuth: Value::Fixed(0.0),
+			distance: Value::Fixed(0.0),
+		}
+	}
+}
@@ -0,0 +1,27 @@
+use crate::{command::ValueChangeCommand, tween::Tween, Value};
+
+use super::CommandWriters;
+
+/// Controls a [`Spatial`](super::Spatial) effect.
+#[derive(Debug)]
+pub struct SpatialHandle {
+	pub(super) command_writers: CommandWriters,
+}
+
+impl SpatialHandle {
+	/// Sets the angle of the sound relative to the listener, in degrees.
+	pub fn set_azimuth(&mut self, azimuth: impl Into<Value<f64>>, tween: Tween) {
+		self.command_writers.set_azimuth.write(ValueChangeCommand {
+			target: azimuth.into(),
+			tween,
+		});
+	}
+
+	/// Sets the distance of the sound from the listener, from `0.0` to `1.0`.
+	pub fn set_distance(&mut self, distance: impl Into<Value<f64>>, tween: Tween) {
+		self.command_writers.set_distance.write(ValueChangeCommand {
+			target: distance.into(),
+			tween,
+		});
+	}
+}
@@ -1,9 +1,38 @@
+use std::f32::consts::FRAC_PI_2;
 use std::ops::{
 	Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
 use crate::{tween::Tweenable, Value};
 
+/// The curve used to map a [`Panning`] value to left/right gains.
+///
+/// Shared between the `Panning` sound setting and the
+/// [`PanningControl`](crate::effect::panning_control::PanningControl) effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PanningLaw {
+	/// Pans by scaling the left and right channels linearly.
+	///
+	/// This is cheap to compute, but the perceived loudness of the sound
+	/// dips as it moves across the center of the stereo field (the
+	/// "hole in the middle" problem).
+	Linear,
+	/// Pans using a constant-power (equal-power) curve.
+	///
+	/// The left and right gains are derived from `cos`/`sin` so that
+	/// `gain_left² + gain_right²` stays constant as the pan value
+	/// changes, keeping the perceived loudness even across the stereo
+	/// field.
+	EqualPower,
+}
+
+impl Default for PanningLaw {
+	fn default() -> Self {
+		Self::Linear
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The stereo positioning of a sound.
@@ -22,6 +51,17 @@ impl Panning {
 	pub const CENTER: Self = Self(0.0);
 	/// Play the sound from the right speaker only.
 	pub const RIGHT: Self = Self(1.0);
+
+	/// Computes the left/right gain multipliers for a mono signal panned
+	/// by this value, using the given panning law.
+	#[must_use]
+	pub fn gains(&self, law: PanningLaw) -> (f32, f32) {
+		let x = (self.0 + 1.0) / 2.0;
+		match law {
+			PanningLaw::Linear => (1.0 - x, x),
+			PanningLaw::EqualPower => ((x * FRAC_PI_2).cos(), (x * FRAC_PI_2).sin()),
+		}
+	}
 }
 
 impl Default for Panning {
@@ -204,3 +244,32 @@ impl Panning {
 		bounded(self.0 % v)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn linear_gains_split_evenly_at_center() {
+		let (left, right) = Panning::CENTER.gains(PanningLaw::Linear);
+		assert!((left - 0.5).abs() < 1e-6);
+		assert!((right - 0.5).abs() < 1e-6);
+	}
+
+	#[test]
+	fn equal_power_gains_are_constant_power() {
+		for pan in [-1.0, -0.5, -0.1, 0.0, 0.1, 0.5, 1.0] {
+			let (left, right) = Panning::from(pan).gains(PanningLaw::EqualPower);
+			let power = left * left + right * right;
+			assert!((power - 1.0).abs() < 1e-6, "power was {power} at pan {pan}");
+		}
+	}
+
+	#[test]
+	fn equal_power_gains_are_hard_panned_at_the_extremes() {
+		let (left, right) = Panning::LEFT.gains(PanningLaw::EqualPower);
+		assert!(left > right);
+		let (left, right) = Panning::RIGHT.gains(PanningLaw::EqualPower);
+		assert!(right > left);
+	}
+}
@@ -0,0 +1,54 @@
+//! Generates a tone procedurally instead of playing back sampled audio.
+
+mod builder;
+mod handle;
+mod sound;
+
+pub use builder::*;
+pub use handle::*;
+
+use crate::{command::ValueChangeCommand, command_writers_and_readers};
+
+use self::sound::OscillatorSound;
+
+use super::{Sound, SoundData};
+
+/// The shape of the waveform an [`OscillatorSoundData`] generates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+	/// A smooth, pure tone.
+	Sine,
+	/// A buzzy tone with a hollow timbre, common in retro chiptunes.
+	Square,
+	/// A mellow tone, softer than a square wave but brighter than a sine wave.
+	Triangle,
+	/// A bright, buzzy tone with a ramping shape.
+	Sawtooth,
+}
+
+/// A procedurally generated tone.
+///
+/// Unlike [`StaticSoundData`](super::static_sound::StaticSoundData), this
+/// doesn't play back sampled audio - it synthesizes a waveform each frame
+/// from a phase accumulator, so it's useful for beeps, retro chip tones,
+/// and test signals without needing any asset files.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OscillatorSoundData(pub OscillatorBuilder);
+
+impl SoundData for OscillatorSoundData {
+	type Error = std::convert::Infallible;
+	type Handle = OscillatorHandle;
+
+	fn into_sound(self) -> Result<(Box<dyn Sound>, Self::Handle), Self::Error> {
+		let (command_writers, command_readers) = command_writers_and_readers();
+		let sound = OscillatorSound::new(self.0, command_readers);
+		let handle = OscillatorHandle { command_writers };
+		Ok((Box::new(sound), handle))
+	}
+}
+
+command_writers_and_readers! {
+	set_frequency: ValueChangeCommand<f64>,
+	set_amplitude: ValueChangeCommand<f64>,
+	set_fade_volume: ValueChangeCommand<f64>,
+}
@@ -0,0 +1,50 @@
+use crate::Value;
+
+use super::Waveform;
+
+/// Configures an [`OscillatorSoundData`](super::OscillatorSoundData).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OscillatorBuilder {
+	/// The waveform the oscillator generates.
+	pub waveform: Waveform,
+	/// The frequency of the generated tone, in hertz.
+	pub frequency: Value<f64>,
+	/// The amplitude of the generated tone, from `0.0` to `1.0`.
+	pub amplitude: Value<f64>,
+}
+
+impl OscillatorBuilder {
+	/// Creates a new [`OscillatorBuilder`] with the given waveform and frequency.
+	#[must_use]
+	pub fn new(waveform: Waveform, frequency: impl Into<Value<f64>>) -> Self {
+		Self {
+			waveform,
+			frequency: frequency.into(),
+			amplitude: Value::Fixed(1.0),
+		}
+	}
+
+	/// Sets the waveform the oscillator generates.
+	#[must_use]
+	pub fn waveform(self, waveform: Waveform) -> Self {
+		Self { waveform, ..self }
+	}
+
+	/// Sets the frequency of the generated tone, in hertz.
+	#[must_use]
+	pub fn frequency(self, frequency: impl Into<Value<f64>>) -> Self {
+		Self {
+			frequency: frequency.into(),
+			..self
+		}
+	}
+
+	/// Sets the amplitude of the generated tone, from `0.0` to `1.0`.
+	#[must_use]
+	pub fn amplitude(self, amplitude: impl Into<Value<f64>>) -> Self {
+		Self {
+			amplitude: amplitude.into(),
+			..self
+		}
+	}
+}
@@ -0,0 +1,39 @@
+use crate::{command::ValueChangeCommand, tween::Tween, Value};
+
+use super::CommandWriters;
+
+/// Controls an [`OscillatorSoundData`](super::OscillatorSoundData) that's
+/// currently playing.
+#[derive(Debug)]
+pub struct OscillatorHandle {
+	pub(super) command_writers: CommandWriters,
+}
+
+impl OscillatorHandle {
+	/// Sets the frequency of the generated tone, in hertz.
+	pub fn set_frequency(&mut self, frequency: impl Into<Value<f64>>, tween: Tween) {
+		self.command_writers.set_frequency.write(ValueChangeCommand {
+			target: frequency.into(),
+			tween,
+		});
+	}
+
+	/// Sets the amplitude of the generated tone, from `0.0` to `1.0`.
+	pub fn set_amplitude(&mut self, amplitude: impl Into<Value<f64>>, tween: Tween) {
+		self.command_writers.set_amplitude.write(ValueChangeCommand {
+			target: amplitude.into(),
+			tween,
+		});
+	}
+
+	/// Fades the tone out and stops it.
+	///
+	/// Once the fade-out finishes, the sound reports itself as finished
+	/// and is removed from the mixer.
+	pub fn stop(&mut self, tween: Tween) {
+		self.command_writers.set_fade_volume.write(ValueChangeCommand {
+			target: Value::Fixed(0.0),
+			tween,
+		});
+	}
+}
@@ -0,0 +1,121 @@
+use std::f64::consts::TAU;
+
+use crate::{
+	command::read_commands_into_parameters, frame::Frame, info::Info, sound::Sound, tween::Parameter,
+	Value,
+};
+
+use super::{CommandReaders, OscillatorBuilder, Waveform};
+
+pub(super) struct OscillatorSound {
+	command_readers: CommandReaders,
+	waveform: Waveform,
+	frequency: Parameter,
+	amplitude: Parameter,
+	/// Tweens from `1.0` down to `0.0` when [`stop`](super::OscillatorHandle::stop)
+	/// is called, so the sound can fade out before it's reported as finished.
+	fade_volume: Parameter,
+	phase: f64,
+}
+
+impl OscillatorSound {
+	#[must_use]
+	pub(super) fn new(builder: OscillatorBuilder, command_readers: CommandReaders) -> Self {
+		Self {
+			command_readers,
+			waveform: builder.waveform,
+			frequency: Parameter::new(builder.frequency, 440.0),
+			amplitude: Parameter::new(builder.amplitude, 1.0),
+			fade_volume: Parameter::new(Value::Fixed(1.0), 1.0),
+			phase: 0.0,
+		}
+	}
+}
+
+impl Sound for OscillatorSound {
+	fn on_start_processing(&mut self) {
+		read_commands_into_parameters!(self, frequency, amplitude, fade_volume);
+	}
+
+	fn process(&mut self, dt: f64, info: &Info) -> Frame {
+		self.frequency.update(dt, info);
+		self.amplitude.update(dt, info);
+		self.fade_volume.update(dt, info);
+
+		let sample =
+			self.waveform.amplitude_at(self.phase) * self.amplitude.value() * self.fade_volume.value();
+
+		self.phase += self.frequency.value() * dt;
+		self.phase %= 1.0;
+
+		Frame::new(sample as f32, sample as f32)
+	}
+
+	fn finished(&self) -> bool {
+		self.fade_volume.value() <= 0.0
+	}
+}
+
+impl Waveform {
+	fn amplitude_at(&self, phase: f64) -> f64 {
+		match self {
+			Waveform::Sine => (phase * TAU).sin(),
+			Waveform::Square => {
+				if phase < 0.5 {
+					1.0
+				} else {
+					-1.0
+				}
+			}
+			Waveform::Triangle => {
+				if phase < 0.25 {
+					4.0 * phase
+				} else if phase < 0.75 {
+					2.0 - 4.0 * phase
+				} else {
+					4.0 * phase - 4.0
+				}
+			}
+			Waveform::Sawtooth => 2.0 * (phase - phase.round()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sine_starts_at_zero_and_peaks_at_a_quarter_phase() {
+		assert_eq!(Waveform::Sine.amplitude_at(0.0), 0.0);
+		assert!((Waveform::Sine.amplitude_at(0.25) - 1.0).abs() < 1e-10);
+	}
+
+	#[test]
+	fn square_is_hard_high_then_hard_low() {
+		assert_eq!(Waveform::Square.amplitude_at(0.0), 1.0);
+		assert_eq!(Waveform::Square.amplitude_at(0.49), 1.0);
+		assert_eq!(Waveform::Square.amplitude_at(0.5), -1.0);
+		assert_eq!(Waveform::Square.amplitude_at(0.99), -1.0);
+	}
+
+	#[test]
+	fn triangle_stays_within_range_and_peaks_at_a_quarter_phase() {
+		for i in 0..100 {
+			let phase = i as f64 / 100.0;
+			let amplitude = Waveform::Triangle.amplitude_at(phase);
+			assert!((-1.0..=1.0).contains(&amplitude));
+		}
+		assert!((Waveform::Triangle.amplitude_at(0.25) - 1.0).abs() < 1e-10);
+	}
+
+	#[test]
+	fn sawtooth_stays_within_range_and_ramps_upward() {
+		for i in 0..100 {
+			let phase = i as f64 / 100.0;
+			let amplitude = Waveform::Sawtooth.amplitude_at(phase);
+			assert!((-1.0..=1.0).contains(&amplitude));
+		}
+		assert!(Waveform::Sawtooth.amplitude_at(0.1) < Waveform::Sawtooth.amplitude_at(0.4));
+	}
+}